@@ -40,47 +40,92 @@
 //! }
 //! ```
 //!
+use std::path::Path;
 use std::ptr;
+use std::sync::{mpsc, Mutex};
 use windows::{
-    core::Interface,
+    core::{implement, Interface, PWSTR, GUID},
     Win32::{
-        Foundation::{CloseHandle, MAX_PATH},
+        Devices::FunctionDiscovery::PKEY_Device_FriendlyName,
+        Foundation::{CloseHandle, BOOL, MAX_PATH},
         Media::Audio::{
-            eRender, IAudioSessionControl, IAudioSessionControl2, IAudioSessionEnumerator,
-            IAudioSessionManager2, IMMDeviceCollection, IMMDeviceEnumerator, ISimpleAudioVolume,
+            eAll, eCapture, eConsole, eRender, AudioSessionDisconnectReason, AudioSessionState,
+            EDataFlow, Endpoints::IAudioEndpointVolume, IAudioMeterInformation,
+            IAudioSessionControl, IAudioSessionControl2, IAudioSessionEnumerator,
+            IAudioSessionEvents, IAudioSessionEvents_Impl, IAudioSessionManager2,
+            IAudioSessionNotification, IAudioSessionNotification_Impl, IChannelAudioVolume,
+            IMMDevice, IMMDeviceCollection, IMMDeviceEnumerator, ISimpleAudioVolume,
             MMDeviceEnumerator, DEVICE_STATE_ACTIVE,
         },
         System::{
-            Com::{CoCreateInstance, CoInitialize, CoUninitialize, CLSCTX_ALL},
+            Com::{
+                CoCreateInstance, CoInitialize, CoTaskMemFree, CoUninitialize,
+                StructuredStorage::PropVariantToStringAlloc, CLSCTX_ALL, STGM_READ,
+            },
             ProcessStatus::GetModuleFileNameExW,
             Threading::{OpenProcess, PROCESS_QUERY_INFORMATION, PROCESS_VM_READ},
         },
+        UI::Shell::PropertiesSystem::IPropertyStore,
     },
 };
 use windows_result::Error;
 
+/// Which kind of audio endpoints to enumerate sessions from, passed to [WinMix::enumerate_for].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DataFlow {
+    /// Render (playback) endpoints only, e.g. speakers and headphones.
+    Render,
+    /// Capture (recording) endpoints only, e.g. microphones.
+    Capture,
+    /// Both render and capture endpoints.
+    All,
+}
+
+impl From<DataFlow> for EDataFlow {
+    fn from(flow: DataFlow) -> Self {
+        match flow {
+            DataFlow::Render => eRender,
+            DataFlow::Capture => eCapture,
+            DataFlow::All => eAll,
+        }
+    }
+}
+
 pub struct WinMix {
     // Whether or not we initialized COM; if so, we have to clean up later
     com_initialized: bool,
 }
 
 impl WinMix {
-    /// Enumerate all audio sessions from all audio endpoints via WASAPI.
+    /// Enumerate all audio sessions from all render (playback) endpoints via WASAPI.
+    ///
+    /// This is a shorthand for `enumerate_for(DataFlow::Render)`.
     ///
     /// # Safety
     /// This function calls other unsafe functions from the [windows](https://crates.io/crates/windows) crate.
     pub unsafe fn enumerate(&self) -> Result<Vec<Session>, Error> {
+        self.enumerate_for(DataFlow::Render)
+    }
+
+    /// Enumerate all audio sessions from all endpoints matching `flow` via WASAPI.
+    ///
+    /// Use [DataFlow::Capture] to find, for example, sessions recording from a microphone.
+    ///
+    /// # Safety
+    /// This function calls other unsafe functions from the [windows](https://crates.io/crates/windows) crate.
+    pub unsafe fn enumerate_for(&self, flow: DataFlow) -> Result<Vec<Session>, Error> {
         let mut result = Vec::<Session>::new();
 
         let res: IMMDeviceEnumerator = CoCreateInstance(&MMDeviceEnumerator, None, CLSCTX_ALL)?;
 
         let collection: IMMDeviceCollection =
-            res.EnumAudioEndpoints(eRender, DEVICE_STATE_ACTIVE)?;
+            res.EnumAudioEndpoints(flow.into(), DEVICE_STATE_ACTIVE)?;
 
         let device_count = collection.GetCount()?;
 
         for device_id in 0..device_count {
             let dev = collection.Item(device_id)?;
+            let device_name = device_friendly_name(&dev)?;
 
             let manager: IAudioSessionManager2 = dev.Activate(CLSCTX_ALL, None)?;
             let enumerator: IAudioSessionEnumerator = manager.GetSessionEnumerator()?;
@@ -91,44 +136,165 @@ impl WinMix {
                 let ctrl: IAudioSessionControl = enumerator.GetSession(session_id)?;
                 let ctrl2: IAudioSessionControl2 = ctrl.cast()?;
 
-                let pid = ctrl2.GetProcessId()?;
-
-                if pid == 0 {
-                    // System sounds session, so we ignore it.
-                    //
-                    // We use this PID == 0 hack because ctrl2.IsSystemSoundsSession() from the windows crate doesn't work yet.
-                    // https://github.com/microsoft/win32metadata/issues/1664
-                    continue;
-                }
+                result.push(build_session(ctrl2, device_name.clone())?);
+            }
+        }
 
-                let proc = OpenProcess(PROCESS_QUERY_INFORMATION | PROCESS_VM_READ, false, pid)?;
+        Ok(result)
+    }
 
-                let mut path: [u16; MAX_PATH as usize] = [0; MAX_PATH as usize];
+    /// Watch for audio sessions being created across all render endpoints, without having
+    /// to re-run [WinMix::enumerate] in a loop.
+    ///
+    /// Returns an RAII guard that stops watching when dropped, and a receiver that yields a
+    /// [Session] each time WASAPI reports a new one (e.g. a program starting to play audio).
+    /// Sessions that already existed at the time of the call are not reported; use
+    /// [WinMix::enumerate] for those.
+    ///
+    /// # Safety
+    /// This function calls other unsafe functions from the [windows](https://crates.io/crates/windows) crate.
+    pub unsafe fn watch_sessions(&self) -> Result<(SessionWatchGuard, mpsc::Receiver<Session>), Error> {
+        let (sender, receiver) = mpsc::channel();
 
-                let res = GetModuleFileNameExW(proc, None, &mut path);
-                CloseHandle(proc)?;
+        let dev_enumerator: IMMDeviceEnumerator =
+            CoCreateInstance(&MMDeviceEnumerator, None, CLSCTX_ALL)?;
+        let collection: IMMDeviceCollection =
+            dev_enumerator.EnumAudioEndpoints(eRender, DEVICE_STATE_ACTIVE)?;
 
-                if res == 0 {
-                    // Failed to get filename from PID (insufficient permissions?)
-                    //continue
-                }
+        let device_count = collection.GetCount()?;
+        let mut managers = Vec::with_capacity(device_count as usize);
+        let mut notifications = Vec::with_capacity(device_count as usize);
 
-                let vol: ISimpleAudioVolume = ctrl2.cast()?;
+        for device_id in 0..device_count {
+            let dev = collection.Item(device_id)?;
+            let device_name = device_friendly_name(&dev)?;
+            let manager: IAudioSessionManager2 = dev.Activate(CLSCTX_ALL, None)?;
 
-                // Trim trailing \0
-                let mut path = String::from_utf16_lossy(&path);
-                path.truncate(path.trim_matches(char::from(0)).len());
+            // Session notifications only fire reliably once the session enumerator has been
+            // fetched at least once, so we do that here during setup.
+            let _: IAudioSessionEnumerator = manager.GetSessionEnumerator()?;
 
-                result.push(Session {
-                    pid,
-                    path,
-                    vol: SimpleVolume { handle: vol },
-                });
+            // Each device gets its own notification object so a newly created session can be
+            // tagged with the friendly name of the device it belongs to.
+            let notification: IAudioSessionNotification = SessionNotificationHandler {
+                sender: Mutex::new(sender.clone()),
+                device_name,
             }
+            .into();
+
+            manager.RegisterSessionNotification(&notification)?;
+            managers.push(manager);
+            notifications.push(notification);
+        }
+
+        Ok((
+            SessionWatchGuard {
+                managers,
+                notifications,
+            },
+            receiver,
+        ))
+    }
+
+    /// List every active audio render endpoint on the system.
+    ///
+    /// Unlike [WinMix::enumerate], which returns one entry per *application* playing audio,
+    /// this returns one entry per *device* (e.g. "Speakers", "Headphones").
+    ///
+    /// # Safety
+    /// This function calls other unsafe functions from the [windows](https://crates.io/crates/windows) crate.
+    pub unsafe fn devices(&self) -> Result<Vec<Device>, Error> {
+        let enumerator: IMMDeviceEnumerator = CoCreateInstance(&MMDeviceEnumerator, None, CLSCTX_ALL)?;
+        let collection: IMMDeviceCollection =
+            enumerator.EnumAudioEndpoints(eRender, DEVICE_STATE_ACTIVE)?;
+
+        let device_count = collection.GetCount()?;
+        let mut result = Vec::with_capacity(device_count as usize);
+
+        for device_id in 0..device_count {
+            let dev = collection.Item(device_id)?;
+            result.push(Device::from_immdevice(&dev)?);
         }
 
         Ok(result)
     }
+
+    /// Get the default render (playback) endpoint, i.e. the device selected in Windows'
+    /// sound settings.
+    ///
+    /// # Safety
+    /// This function calls other unsafe functions from the [windows](https://crates.io/crates/windows) crate.
+    pub unsafe fn default_render_device(&self) -> Result<Device, Error> {
+        let enumerator: IMMDeviceEnumerator = CoCreateInstance(&MMDeviceEnumerator, None, CLSCTX_ALL)?;
+        let dev = enumerator.GetDefaultAudioEndpoint(eRender, eConsole)?;
+
+        Device::from_immdevice(&dev)
+    }
+
+    /// Get every render session owned by the given PID.
+    ///
+    /// A single process can own several sessions across devices, so this may return more
+    /// than one [Session].
+    ///
+    /// # Safety
+    /// This function calls other unsafe functions from the [windows](https://crates.io/crates/windows) crate.
+    pub unsafe fn sessions_for_pid(&self, pid: u32) -> Result<Vec<Session>, Error> {
+        Ok(self
+            .enumerate()?
+            .into_iter()
+            .filter(|session| session.pid == pid)
+            .collect())
+    }
+
+    /// Get every render session whose process' executable file name matches `name`,
+    /// case-insensitively (e.g. `"chrome.exe"`).
+    ///
+    /// # Safety
+    /// This function calls other unsafe functions from the [windows](https://crates.io/crates/windows) crate.
+    pub unsafe fn sessions_for_exe(&self, name: &str) -> Result<Vec<Session>, Error> {
+        Ok(self
+            .enumerate()?
+            .into_iter()
+            .filter(|session| exe_name_matches(&session.path, name))
+            .collect())
+    }
+
+    /// Set the master volume on every render session belonging to `name` (e.g. `"chrome.exe"`),
+    /// leaving every other session untouched.
+    ///
+    /// * `level` - the volume level, between `0.0` and `1.0`
+    ///
+    /// # Safety
+    /// This function calls other unsafe functions from the [windows](https://crates.io/crates/windows) crate.
+    pub unsafe fn set_volume_for_exe(&self, name: &str, level: f32) -> Result<(), Error> {
+        for session in self.sessions_for_exe(name)? {
+            session.vol.set_master_volume(level)?;
+        }
+        Ok(())
+    }
+
+    /// Mute or unmute every render session belonging to `name` (e.g. `"chrome.exe"`), leaving
+    /// every other session untouched.
+    ///
+    /// * `val` - `true` to mute, `false` to unmute
+    ///
+    /// # Safety
+    /// This function calls other unsafe functions from the [windows](https://crates.io/crates/windows) crate.
+    pub unsafe fn mute_exe(&self, name: &str, val: bool) -> Result<(), Error> {
+        for session in self.sessions_for_exe(name)? {
+            session.vol.set_mute(val)?;
+        }
+        Ok(())
+    }
+}
+
+// Matches the file name component of `path` against `name`, case-insensitively.
+fn exe_name_matches(path: &str, name: &str) -> bool {
+    Path::new(path)
+        .file_name()
+        .and_then(|file_name| file_name.to_str())
+        .map(|file_name| file_name.eq_ignore_ascii_case(name))
+        .unwrap_or(false)
 }
 
 impl Default for WinMix {
@@ -157,13 +323,341 @@ impl Drop for WinMix {
     }
 }
 
+// Reads a COM-allocated wide string and frees it, returning an owned Rust string.
+unsafe fn take_pwstr(raw: PWSTR) -> String {
+    let s = raw.to_string().unwrap_or_default();
+    CoTaskMemFree(Some(raw.0 as _));
+    s
+}
+
+// Reads the friendly name of an audio endpoint, e.g. "Speakers (Realtek High Definition Audio)".
+// Shared between Device::from_immdevice, WinMix::enumerate_for and WinMix::watch_sessions; the
+// latter two pass it to build_session, which tags each Session with the friendly name of the
+// device it was enumerated from.
+unsafe fn device_friendly_name(dev: &IMMDevice) -> Result<String, Error> {
+    let store: IPropertyStore = dev.OpenPropertyStore(STGM_READ)?;
+    let prop = store.GetValue(&PKEY_Device_FriendlyName)?;
+
+    let mut raw_name = PWSTR::null();
+    PropVariantToStringAlloc(&prop, &mut raw_name)?;
+    Ok(take_pwstr(raw_name))
+}
+
+// Builds a Session from an IAudioSessionControl2, resolving its PID, path, and metadata.
+// `device_name` is the friendly name of the device it was enumerated from. Shared between
+// WinMix::enumerate_for and the session-created notification handler.
+unsafe fn build_session(ctrl2: IAudioSessionControl2, device_name: String) -> Result<Session, Error> {
+    let pid = ctrl2.GetProcessId()?;
+
+    // We use this PID == 0 hack because ctrl2.IsSystemSoundsSession() from the
+    // windows crate doesn't work yet.
+    // https://github.com/microsoft/win32metadata/issues/1664
+    let is_system_sounds = pid == 0;
+
+    let path = if is_system_sounds {
+        String::new()
+    } else {
+        let proc = OpenProcess(PROCESS_QUERY_INFORMATION | PROCESS_VM_READ, false, pid)?;
+
+        let mut path: [u16; MAX_PATH as usize] = [0; MAX_PATH as usize];
+
+        let res = GetModuleFileNameExW(proc, None, &mut path);
+        CloseHandle(proc)?;
+
+        if res == 0 {
+            // Failed to get filename from PID (insufficient permissions?)
+            //continue
+        }
+
+        // Trim trailing \0
+        let mut path = String::from_utf16_lossy(&path);
+        path.truncate(path.trim_matches(char::from(0)).len());
+        path
+    };
+
+    let vol: ISimpleAudioVolume = ctrl2.cast()?;
+    let channel_vol: Option<IChannelAudioVolume> = ctrl2.cast().ok();
+    let meter: Option<IAudioMeterInformation> = ctrl2.cast().ok();
+
+    let mut display_name = take_pwstr(ctrl2.GetDisplayName()?);
+    if is_system_sounds && display_name.is_empty() {
+        display_name = "System Sounds".to_string();
+    }
+
+    let icon_path = take_pwstr(ctrl2.GetIconPath()?);
+    let grouping_param = ctrl2.GetGroupingParam()?;
+    let state = ctrl2.GetState()?;
+
+    Ok(Session {
+        pid,
+        path,
+        device_name,
+        display_name,
+        icon_path,
+        grouping_param,
+        state,
+        is_system_sounds,
+        vol: SimpleVolume { handle: vol },
+        channel_vol: channel_vol.map(|handle| ChannelVolume { handle }),
+        meter: meter.map(|handle| Meter { handle }),
+        ctrl2,
+    })
+}
+
 pub struct Session {
     /// The PID of the process that controls this audio session.
     pub pid: u32,
     /// The exe path for the process that controls this audio session.
+    ///
+    /// Empty for the system sounds session, since it isn't backed by a process.
     pub path: String,
+    /// The friendly name of the audio endpoint this session belongs to, e.g.
+    /// `"Speakers (Realtek High Definition Audio)"`. For a render session this matches
+    /// [Device::name] for the device returned by [WinMix::devices]/[WinMix::default_render_device];
+    /// those list render endpoints only, so a capture session's device is not among them.
+    pub device_name: String,
+    /// The display name WASAPI has associated with this session, if any.
+    ///
+    /// For the system sounds session this is synthesized as `"System Sounds"` when
+    /// WASAPI doesn't report one of its own, so callers can identify and mute it.
+    pub display_name: String,
+    /// The path to the icon WASAPI has associated with this session, if any.
+    pub icon_path: String,
+    /// The grouping parameter GUID for this session, used by WASAPI to group related
+    /// sessions (e.g. multiple tabs of the same browser) together in volume UI.
+    pub grouping_param: GUID,
+    /// Whether this session is currently active, inactive, or expired.
+    pub state: AudioSessionState,
+    /// Whether this is the special system sounds session, e.g. for Windows notification sounds.
+    pub is_system_sounds: bool,
     /// A wrapper that lets you control the volume for this audio session.
     pub vol: SimpleVolume,
+    /// A wrapper that lets you control the per-channel volume for this audio session,
+    /// if the session control exposes `IChannelAudioVolume`.
+    pub channel_vol: Option<ChannelVolume>,
+    /// A wrapper that lets you read the live peak output level for this audio session,
+    /// if the session control exposes `IAudioMeterInformation`.
+    pub meter: Option<Meter>,
+    // Kept around so we can register for session-level COM notifications on demand.
+    ctrl2: IAudioSessionControl2,
+}
+
+impl Session {
+    /// Subscribe to volume, mute, and session-state changes for this session.
+    ///
+    /// The changes may come from our own calls, from other applications (e.g. sndvol),
+    /// or from the owning process itself.
+    ///
+    /// Because COM delivers these callbacks on an arbitrary thread, they are marshalled
+    /// through an [`mpsc`] channel so the returned [`mpsc::Receiver`] can be polled from
+    /// a normal thread. Dropping the returned [`SessionEventsGuard`] unregisters the
+    /// subscription.
+    ///
+    /// # Safety
+    /// This function calls [IAudioSessionControl2.RegisterAudioSessionNotification](https://learn.microsoft.com/en-us/windows/win32/api/audiopolicy/nf-audiopolicy-iaudiosessioncontrol-registeraudiosessionnotification) which is unsafe.
+    pub unsafe fn register_events(
+        &self,
+    ) -> Result<(SessionEventsGuard, mpsc::Receiver<SessionEvent>), Error> {
+        let (sender, receiver) = mpsc::channel();
+
+        let events: IAudioSessionEvents = SessionEventsHandler {
+            sender: Mutex::new(sender),
+        }
+        .into();
+
+        self.ctrl2.RegisterAudioSessionNotification(&events)?;
+
+        Ok((
+            SessionEventsGuard {
+                ctrl2: self.ctrl2.clone(),
+                events,
+            },
+            receiver,
+        ))
+    }
+}
+
+/// An event reported for a [`Session`] that has been subscribed to via [`Session::register_events`].
+#[derive(Debug, Clone)]
+pub enum SessionEvent {
+    /// The session's volume level and/or mute state changed.
+    SimpleVolumeChanged {
+        /// The new volume level, between `0.0` and `1.0`.
+        level: f32,
+        /// Whether the session is now muted.
+        muted: bool,
+        /// The event context GUID supplied by whoever made the change, if any.
+        context: GUID,
+    },
+    /// The session changed state, e.g. became active, inactive, or expired.
+    StateChanged(AudioSessionState),
+    /// The session's display name changed.
+    DisplayNameChanged(String),
+}
+
+/// An RAII guard returned by [`Session::register_events`].
+///
+/// Unregisters the session notification and releases the underlying COM references
+/// when dropped.
+pub struct SessionEventsGuard {
+    ctrl2: IAudioSessionControl2,
+    // Kept alive for the lifetime of the guard, since WASAPI only holds a weak reference.
+    events: IAudioSessionEvents,
+}
+
+impl Drop for SessionEventsGuard {
+    fn drop(&mut self) {
+        unsafe {
+            let _ = self.ctrl2.UnregisterAudioSessionNotification(&self.events);
+        }
+    }
+}
+
+#[implement(IAudioSessionEvents)]
+struct SessionEventsHandler {
+    sender: Mutex<mpsc::Sender<SessionEvent>>,
+}
+
+#[allow(non_snake_case)]
+impl IAudioSessionEvents_Impl for SessionEventsHandler_Impl {
+    fn OnDisplayNameChanged(
+        &self,
+        newdisplayname: &windows::core::PCWSTR,
+        _eventcontext: *const GUID,
+    ) -> windows::core::Result<()> {
+        let name = unsafe { newdisplayname.to_string().unwrap_or_default() };
+        let _ = self
+            .sender
+            .lock()
+            .unwrap()
+            .send(SessionEvent::DisplayNameChanged(name));
+        Ok(())
+    }
+
+    fn OnIconPathChanged(
+        &self,
+        _newiconpath: &windows::core::PCWSTR,
+        _eventcontext: *const GUID,
+    ) -> windows::core::Result<()> {
+        Ok(())
+    }
+
+    fn OnSimpleVolumeChanged(
+        &self,
+        newvolume: f32,
+        newmute: BOOL,
+        eventcontext: *const GUID,
+    ) -> windows::core::Result<()> {
+        let context = unsafe {
+            if eventcontext.is_null() {
+                GUID::zeroed()
+            } else {
+                *eventcontext
+            }
+        };
+
+        let _ = self.sender.lock().unwrap().send(SessionEvent::SimpleVolumeChanged {
+            level: newvolume,
+            muted: newmute.as_bool(),
+            context,
+        });
+        Ok(())
+    }
+
+    fn OnChannelVolumeChanged(
+        &self,
+        _channelcount: u32,
+        _newchannelvolumearray: *const f32,
+        _changedchannel: u32,
+        _eventcontext: *const GUID,
+    ) -> windows::core::Result<()> {
+        Ok(())
+    }
+
+    fn OnGroupingParamChanged(
+        &self,
+        _newgroupingparam: *const GUID,
+        _eventcontext: *const GUID,
+    ) -> windows::core::Result<()> {
+        Ok(())
+    }
+
+    fn OnStateChanged(&self, newstate: AudioSessionState) -> windows::core::Result<()> {
+        let _ = self.sender.lock().unwrap().send(SessionEvent::StateChanged(newstate));
+        Ok(())
+    }
+
+    fn OnSessionDisconnected(
+        &self,
+        _disconnectreason: AudioSessionDisconnectReason,
+    ) -> windows::core::Result<()> {
+        Ok(())
+    }
+}
+
+/// An RAII guard returned by [`WinMix::watch_sessions`].
+///
+/// Stops watching for new sessions and releases the underlying COM references when dropped.
+pub struct SessionWatchGuard {
+    managers: Vec<IAudioSessionManager2>,
+    // Kept alive for the lifetime of the guard, since WASAPI only holds a weak reference.
+    // One per device, in the same order as `managers`.
+    notifications: Vec<IAudioSessionNotification>,
+}
+
+impl Drop for SessionWatchGuard {
+    fn drop(&mut self) {
+        unsafe {
+            for (manager, notification) in self.managers.iter().zip(&self.notifications) {
+                let _ = manager.UnregisterSessionNotification(notification);
+            }
+        }
+    }
+}
+
+#[implement(IAudioSessionNotification)]
+struct SessionNotificationHandler {
+    sender: Mutex<mpsc::Sender<Session>>,
+    device_name: String,
+}
+
+#[allow(non_snake_case)]
+impl IAudioSessionNotification_Impl for SessionNotificationHandler_Impl {
+    fn OnSessionCreated(
+        &self,
+        newsession: Option<&IAudioSessionControl>,
+    ) -> windows::core::Result<()> {
+        let Some(ctrl) = newsession else {
+            return Ok(());
+        };
+
+        if let Ok(ctrl2) = ctrl.cast::<IAudioSessionControl2>() {
+            if let Ok(session) = build_session(ctrl2, self.device_name.clone()) {
+                let _ = self.sender.lock().unwrap().send(session);
+            }
+        }
+
+        Ok(())
+    }
+}
+
+pub struct Device {
+    /// The friendly name of this audio endpoint, e.g. `"Speakers (Realtek High Definition Audio)"`.
+    pub name: String,
+    /// A wrapper that lets you control the master volume for this device.
+    pub vol: EndpointVolume,
+}
+
+impl Device {
+    unsafe fn from_immdevice(dev: &IMMDevice) -> Result<Device, Error> {
+        let name = device_friendly_name(dev)?;
+        let vol: IAudioEndpointVolume = dev.Activate(CLSCTX_ALL, None)?;
+
+        Ok(Device {
+            name,
+            vol: EndpointVolume { handle: vol },
+        })
+    }
 }
 
 pub struct SimpleVolume {
@@ -210,3 +704,142 @@ impl SimpleVolume {
         self.handle.SetMute(val, ptr::null())
     }
 }
+
+pub struct ChannelVolume {
+    handle: IChannelAudioVolume,
+}
+
+impl ChannelVolume {
+    /// Get the number of channels in this audio session.
+    ///
+    /// # Safety
+    /// This function calls [IChannelAudioVolume.GetChannelCount](https://learn.microsoft.com/en-us/windows/win32/api/audioclient/nf-audioclient-ichannelaudiovolume-getchannelcount) which is unsafe.
+    pub unsafe fn get_channel_count(&self) -> Result<u32, Error> {
+        self.handle.GetChannelCount()
+    }
+
+    /// Get the volume of a single channel.
+    ///
+    /// * `index` - the zero-based channel index, below [ChannelVolume::get_channel_count]
+    ///
+    /// # Safety
+    /// This function calls [IChannelAudioVolume.GetChannelVolume](https://learn.microsoft.com/en-us/windows/win32/api/audioclient/nf-audioclient-ichannelaudiovolume-getchannelvolume) which is unsafe.
+    pub unsafe fn get_channel_volume(&self, index: u32) -> Result<f32, Error> {
+        self.handle.GetChannelVolume(index)
+    }
+
+    /// Set the volume of a single channel, independently of the others.
+    ///
+    /// * `index` - the zero-based channel index, below [ChannelVolume::get_channel_count]
+    /// * `level` - the volume level, between `0.0` and `1.0`
+    ///
+    /// # Safety
+    /// This function calls [IChannelAudioVolume.SetChannelVolume](https://learn.microsoft.com/en-us/windows/win32/api/audioclient/nf-audioclient-ichannelaudiovolume-setchannelvolume) which is unsafe.
+    pub unsafe fn set_channel_volume(&self, index: u32, level: f32) -> Result<(), Error> {
+        self.handle.SetChannelVolume(index, level, ptr::null())
+    }
+
+    /// Set the volume of every channel at once.
+    ///
+    /// * `levels` - one volume level per channel, between `0.0` and `1.0`; must have exactly
+    ///   [ChannelVolume::get_channel_count] entries
+    ///
+    /// # Safety
+    /// This function calls [IChannelAudioVolume.SetAllVolumes](https://learn.microsoft.com/en-us/windows/win32/api/audioclient/nf-audioclient-ichannelaudiovolume-setallvolumes) which is unsafe.
+    pub unsafe fn set_all_volumes(&self, levels: &[f32]) -> Result<(), Error> {
+        self.handle
+            .SetAllVolumes(levels.len() as u32, levels.as_ptr(), ptr::null())
+    }
+}
+
+pub struct EndpointVolume {
+    handle: IAudioEndpointVolume,
+}
+
+impl EndpointVolume {
+    /// Get the master volume for this device, between `0.0` and `1.0`.
+    ///
+    /// # Safety
+    /// This function calls [IAudioEndpointVolume.GetMasterVolumeLevelScalar](https://learn.microsoft.com/en-us/windows/win32/api/endpointvolume/nf-endpointvolume-iaudioendpointvolume-getmastervolumelevelscalar) which is unsafe.
+    pub unsafe fn get_master_volume_scalar(&self) -> Result<f32, Error> {
+        self.handle.GetMasterVolumeLevelScalar()
+    }
+
+    /// Set the master volume for this device.
+    ///
+    /// * `level` - the volume level, between `0.0` and `1.0`
+    ///
+    /// # Safety
+    /// This function calls [IAudioEndpointVolume.SetMasterVolumeLevelScalar](https://learn.microsoft.com/en-us/windows/win32/api/endpointvolume/nf-endpointvolume-iaudioendpointvolume-setmastervolumelevelscalar) which is unsafe.
+    pub unsafe fn set_master_volume_scalar(&self, level: f32) -> Result<(), Error> {
+        self.handle.SetMasterVolumeLevelScalar(level, ptr::null())
+    }
+
+    /// Check if this device is muted.
+    ///
+    /// # Safety
+    /// This function calls [IAudioEndpointVolume.GetMute](https://learn.microsoft.com/en-us/windows/win32/api/endpointvolume/nf-endpointvolume-iaudioendpointvolume-getmute) which is unsafe.
+    pub unsafe fn get_mute(&self) -> Result<bool, Error> {
+        Ok(self.handle.GetMute()?.as_bool())
+    }
+
+    /// Mute or unmute this device.
+    ///
+    /// * `val` - `true` to mute, `false` to unmute
+    ///
+    /// # Safety
+    /// This function calls [IAudioEndpointVolume.SetMute](https://learn.microsoft.com/en-us/windows/win32/api/endpointvolume/nf-endpointvolume-iaudioendpointvolume-setmute) which is unsafe.
+    pub unsafe fn set_mute(&self, val: bool) -> Result<(), Error> {
+        self.handle.SetMute(val, ptr::null())
+    }
+
+    /// Step the master volume up by one increment, as defined by the endpoint's hardware.
+    ///
+    /// # Safety
+    /// This function calls [IAudioEndpointVolume.VolumeStepUp](https://learn.microsoft.com/en-us/windows/win32/api/endpointvolume/nf-endpointvolume-iaudioendpointvolume-volumestepup) which is unsafe.
+    pub unsafe fn step_up(&self) -> Result<(), Error> {
+        self.handle.VolumeStepUp(ptr::null())
+    }
+
+    /// Step the master volume down by one increment, as defined by the endpoint's hardware.
+    ///
+    /// # Safety
+    /// This function calls [IAudioEndpointVolume.VolumeStepDown](https://learn.microsoft.com/en-us/windows/win32/api/endpointvolume/nf-endpointvolume-iaudioendpointvolume-volumestepdown) which is unsafe.
+    pub unsafe fn step_down(&self) -> Result<(), Error> {
+        self.handle.VolumeStepDown(ptr::null())
+    }
+}
+
+pub struct Meter {
+    handle: IAudioMeterInformation,
+}
+
+impl Meter {
+    /// Get the peak sample value, between `0.0` and `1.0`, seen since the last call.
+    ///
+    /// # Safety
+    /// This function calls [IAudioMeterInformation.GetPeakValue](https://learn.microsoft.com/en-us/windows/win32/api/audioclient/nf-audioclient-iaudiometerinformation-getpeakvalue) which is unsafe.
+    pub unsafe fn get_peak_value(&self) -> Result<f32, Error> {
+        self.handle.GetPeakValue()
+    }
+
+    /// Get the number of channels this meter reports peak values for.
+    ///
+    /// # Safety
+    /// This function calls [IAudioMeterInformation.GetMeteringChannelCount](https://learn.microsoft.com/en-us/windows/win32/api/audioclient/nf-audioclient-iaudiometerinformation-getmeteringchannelcount) which is unsafe.
+    pub unsafe fn get_metering_channel_count(&self) -> Result<u32, Error> {
+        self.handle.GetMeteringChannelCount()
+    }
+
+    /// Get the peak sample value for each channel, between `0.0` and `1.0`, since the last call.
+    ///
+    /// * `peaks` - filled with one peak value per channel; must have exactly
+    ///   [Meter::get_metering_channel_count] entries
+    ///
+    /// # Safety
+    /// This function calls [IAudioMeterInformation.GetChannelsPeakValues](https://learn.microsoft.com/en-us/windows/win32/api/audioclient/nf-audioclient-iaudiometerinformation-getchannelspeakvalues) which is unsafe.
+    pub unsafe fn get_channels_peak_values(&self, peaks: &mut [f32]) -> Result<(), Error> {
+        self.handle
+            .GetChannelsPeakValues(peaks.len() as u32, peaks.as_mut_ptr())
+    }
+}